@@ -1,4 +1,4 @@
-use rustymicrograd::{MultiLayerPerceptron, Value};
+use rustymicrograd::{mse, Activation, MultiLayerPerceptron, Optimizer, Sgd, Value};
 
 fn main() {
     let xs = [
@@ -9,7 +9,12 @@ fn main() {
     ];
     let ys = [1.0, -1.0, -1.0, 1.0].map(|v| Value::new(v, None)); // desired targets
 
-    let nn = MultiLayerPerceptron::new(3, &[4, 4, 1]);
+    let nn = MultiLayerPerceptron::new(
+        3,
+        &[4, 4, 1],
+        &[Activation::Tanh, Activation::Tanh, Activation::Tanh],
+    );
+    let mut opt = Sgd::new(nn.parameters(), 0.01, 0.0, 0.0);
 
     for _ in 1..100 {
         // Calculate predictions for the neural network.
@@ -23,30 +28,17 @@ fn main() {
             "{:?}",
             predictions
                 .iter()
-                .map(|p| p.inner.as_ref().borrow().data)
+                .map(|p| p.inner.borrow().data)
                 .collect::<Vec<_>>()
         );
 
-        // Calculate loss as the Squared Root Errors - the sum of pow((x - y), 2.0)
-        let loss = ys
-            .iter()
-            .zip(predictions)
-            .map(|(y, p)| (y - &p).pow(2.0))
-            .fold(Value::new(0.0, None), |acc, v| acc + v);
+        // Calculate loss as the mean squared error against the targets.
+        let loss = mse(&predictions, &ys);
         println!("{}", loss);
 
-        // Reset gradients.
-        nn.parameters()
-            .iter()
-            .for_each(|p| p.inner.as_ref().borrow_mut().grad = 0.0);
-
-        // Calculate new gradients from loss.
-        loss.inner.as_ref().borrow_mut().grad = 1.0;
-        loss.inner.as_ref().borrow().backward();
-
-        nn.parameters().iter().for_each(|p| {
-            let mut inner = p.inner.as_ref().borrow_mut();
-            inner.data -= 0.01 * inner.grad;
-        });
+        // Calculate new gradients from loss and apply the optimizer step.
+        opt.zero_grad();
+        loss.backward();
+        opt.step();
     }
 }
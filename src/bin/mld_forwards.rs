@@ -1,8 +1,12 @@
-use rustymicrograd::{MultiLayerPerceptron, Value};
+use rustymicrograd::{Activation, MultiLayerPerceptron, Value};
 
 fn main() {
     let x = [2.0, 3.0, -1.0].map(|v| Value::new(v, None));
-    let n = MultiLayerPerceptron::new(3, &[4, 4, 1]);
+    let n = MultiLayerPerceptron::new(
+        3,
+        &[4, 4, 1],
+        &[Activation::Tanh, Activation::Tanh, Activation::Tanh],
+    );
     let v = n.forward(&x);
 
     let preds = v.iter().map(|v| v.inner.borrow().data).collect::<Vec<_>>();
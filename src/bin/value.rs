@@ -14,21 +14,20 @@ fn main() {
 
     // x1*w1 + x2*w2 + b
     let x1w1 = x1 * w1;
-    x1w1.inner.as_ref().borrow_mut().label = Some("x1 * w1");
+    x1w1.inner.borrow_mut().label = Some("x1 * w1");
 
     let x2w2 = x2 * w2;
-    x2w2.inner.as_ref().borrow_mut().label = Some("x2 * w2");
+    x2w2.inner.borrow_mut().label = Some("x2 * w2");
 
     let x1w1x2w2 = x1w1 + x2w2;
-    x1w1x2w2.inner.as_ref().borrow_mut().label = Some("x1 * w1 + x2 * w2");
+    x1w1x2w2.inner.borrow_mut().label = Some("x1 * w1 + x2 * w2");
 
     let n = x1w1x2w2 + b;
-    n.inner.as_ref().borrow_mut().label = Some("n");
+    n.inner.borrow_mut().label = Some("n");
 
     let o = n.tanh();
-    o.inner.as_ref().borrow_mut().label = Some("o");
-    o.inner.as_ref().borrow_mut().grad = 1.0;
-    o.inner.borrow().backward();
+    o.inner.borrow_mut().label = Some("o");
+    o.backward();
 
     debug(&o);
 }
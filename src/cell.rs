@@ -0,0 +1,93 @@
+//! A shared, interior-mutable cell abstracting over the two representations
+//! `Value`'s graph nodes can use: `Rc<RefCell<T>>` (the default,
+//! single-threaded representation) or `Arc<RwLock<T>>` (used when the
+//! `rayon` feature is enabled, so a computation graph built across threads
+//! stays `Send`/`Sync`).
+//!
+//! Both arms expose the same `new`/`borrow`/`borrow_mut`/`as_ptr` surface,
+//! so `value.rs` does not need to know which representation is active.
+
+#[cfg(not(feature = "rayon"))]
+mod imp {
+    use std::cell::{Ref, RefCell, RefMut};
+    use std::rc::Rc;
+
+    #[derive(Debug)]
+    pub struct Shared<T>(Rc<RefCell<T>>);
+
+    impl<T> Shared<T> {
+        pub fn new(value: T) -> Self {
+            Shared(Rc::new(RefCell::new(value)))
+        }
+
+        pub fn borrow(&self) -> Ref<'_, T> {
+            self.0.borrow()
+        }
+
+        pub fn borrow_mut(&self) -> RefMut<'_, T> {
+            self.0.borrow_mut()
+        }
+
+        /// Raw pointer identity of the shared cell, for use as a visited-set
+        /// key when walking a graph that may share nodes between parents.
+        pub fn as_ptr(&self) -> *const () {
+            Rc::as_ptr(&self.0) as *const ()
+        }
+    }
+
+    impl<T> Clone for Shared<T> {
+        fn clone(&self) -> Self {
+            Shared(self.0.clone())
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod imp {
+    use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+    #[derive(Debug)]
+    pub struct Shared<T>(Arc<RwLock<T>>);
+
+    impl<T> Shared<T> {
+        pub fn new(value: T) -> Self {
+            Shared(Arc::new(RwLock::new(value)))
+        }
+
+        /// Blocks until a read lock is available.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the lock is poisoned, i.e. another thread holding it
+        /// panicked - this mirrors `RefCell::borrow`'s panic-on-misuse
+        /// behavior in the single-threaded build.
+        pub fn borrow(&self) -> RwLockReadGuard<'_, T> {
+            self.0.read().expect("Shared lock poisoned")
+        }
+
+        /// Blocks until a write lock is available.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the lock is poisoned, i.e. another thread holding it
+        /// panicked - this mirrors `RefCell::borrow_mut`'s panic-on-misuse
+        /// behavior in the single-threaded build.
+        pub fn borrow_mut(&self) -> RwLockWriteGuard<'_, T> {
+            self.0.write().expect("Shared lock poisoned")
+        }
+
+        /// Raw pointer identity of the shared cell, for use as a visited-set
+        /// key when walking a graph that may share nodes between parents.
+        pub fn as_ptr(&self) -> *const () {
+            Arc::as_ptr(&self.0) as *const ()
+        }
+    }
+
+    impl<T> Clone for Shared<T> {
+        fn clone(&self) -> Self {
+            Shared(self.0.clone())
+        }
+    }
+}
+
+pub use imp::Shared;
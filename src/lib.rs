@@ -1,10 +1,27 @@
+/// A shared, interior-mutable cell abstracting over `Rc<RefCell<_>>` (the
+/// default) and, behind the `rayon` feature, `Arc<RwLock<_>>`.
+mod cell;
+
+/// This module contains loss functions used to train a neural network.
+mod loss;
+
 /// This module contains the implementation of a neural network.
 mod nn;
 
+/// This module contains the optimizers used to train a neural network.
+mod optim;
+
+/// This module contains JSON save/load of a trained network's parameters,
+/// gated behind the `serde` feature.
+#[cfg(feature = "serde")]
+mod persist;
+
 /// This module contains the implementation of a value used in the neural network.
 mod value;
 
+pub use loss::*;
 pub use nn::*;
+pub use optim::*;
 pub use value::*;
 
 /// Prints the debug information of a given `Value`.
@@ -33,7 +50,27 @@ pub fn debug(v: &Value) {
             debug(&v.into());
             println!("{}", v.borrow().op);
         }
-        _ => {}
+        Op::ReLU(v) => {
+            debug(&v.into());
+            println!("{}", v.borrow().op);
+        }
+        Op::Sigmoid(v, _) => {
+            debug(&v.into());
+            println!("{}", v.borrow().op);
+        }
+        Op::Exp(v, _) => {
+            debug(&v.into());
+            println!("{}", v.borrow().op);
+        }
+        Op::Ln(v) => {
+            debug(&v.into());
+            println!("{}", v.borrow().op);
+        }
+        Op::Clamp(v, _, _) => {
+            debug(&v.into());
+            println!("{}", v.borrow().op);
+        }
+        Op::None => {}
     }
     println!("{} | {} | {}", v.label.unwrap_or(""), v.data, v.grad);
 }
@@ -0,0 +1,132 @@
+use crate::Value;
+
+/// Smallest/largest a prediction is clamped to before `ln()` in [`bce`], to
+/// keep the loss finite when a prediction saturates to exactly `0.0`/`1.0`.
+const BCE_EPS: f32 = 1e-7;
+
+/// A loss function wired into the autograd graph: given model predictions
+/// and their targets, produces a single scalar `Value` whose `backward()`
+/// propagates gradients back through every prediction.
+///
+/// Exposing this as a trait lets a future training helper accept whichever
+/// loss the caller wants instead of hardcoding one.
+pub trait Criterion {
+    /// Computes the loss for `predictions` against `targets`.
+    fn loss(&self, predictions: &[Value], targets: &[Value]) -> Value;
+}
+
+/// Mean squared error - see [`mse`].
+pub struct Mse;
+
+impl Criterion for Mse {
+    fn loss(&self, predictions: &[Value], targets: &[Value]) -> Value {
+        mse(predictions, targets)
+    }
+}
+
+/// Binary cross-entropy - see [`bce`]. Pairs naturally with an
+/// `Activation::Sigmoid` output layer.
+pub struct Bce;
+
+impl Criterion for Bce {
+    fn loss(&self, predictions: &[Value], targets: &[Value]) -> Value {
+        bce(predictions, targets)
+    }
+}
+
+/// Mean of `(prediction - target)^2` over all pairs.
+///
+/// # Panics
+///
+/// Panics if `predictions` and `targets` have different lengths, or if
+/// either is empty.
+pub fn mse(predictions: &[Value], targets: &[Value]) -> Value {
+    assert_eq!(
+        predictions.len(),
+        targets.len(),
+        "predictions and targets must have the same length"
+    );
+    assert!(!predictions.is_empty(), "mse requires at least one prediction");
+
+    let n = predictions.len() as f32;
+    let sum = predictions
+        .iter()
+        .zip(targets)
+        .map(|(p, y)| (p - y).pow(2.0))
+        .fold(Value::new(0.0, None), |acc, v| acc + v);
+    sum * (1.0 / n)
+}
+
+/// Binary cross-entropy, averaged over all pairs:
+/// `mean(-[y*ln(p) + (1-y)*ln(1-p)])`.
+///
+/// Each prediction is clamped to `[BCE_EPS, 1.0 - BCE_EPS]` before taking
+/// its logarithm, since `ln(0)` is undefined and a saturated sigmoid output
+/// can reach exactly `0.0`/`1.0` in `f32`.
+///
+/// # Panics
+///
+/// Panics if `predictions` and `targets` have different lengths, or if
+/// either is empty.
+pub fn bce(predictions: &[Value], targets: &[Value]) -> Value {
+    assert_eq!(
+        predictions.len(),
+        targets.len(),
+        "predictions and targets must have the same length"
+    );
+    assert!(!predictions.is_empty(), "bce requires at least one prediction");
+
+    let n = predictions.len() as f32;
+    let one = Value::new(1.0, None);
+    let sum = predictions
+        .iter()
+        .zip(targets)
+        .map(|(p, y)| {
+            let p = p.clamp(BCE_EPS, 1.0 - BCE_EPS);
+            let log_p = p.ln();
+            let log_1_minus_p = (&one - &p).ln();
+            let term = &(y * &log_p) + &(&(&one - y) * &log_1_minus_p);
+            -term
+        })
+        .fold(Value::new(0.0, None), |acc, v| acc + v);
+    sum * (1.0 / n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mse() {
+        let predictions = [Value::new(1.0, None), Value::new(2.0, None)];
+        let targets = [Value::new(0.0, None), Value::new(0.0, None)];
+        let loss = mse(&predictions, &targets);
+        // (1 - 0)^2 + (2 - 0)^2 = 1 + 4 = 5, mean = 2.5
+        assert_eq!(loss.data(), 2.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mse_mismatched_lengths_panics() {
+        let predictions = [Value::new(1.0, None)];
+        let targets = [];
+        mse(&predictions, &targets);
+    }
+
+    #[test]
+    fn test_bce_perfect_prediction_is_near_zero() {
+        let predictions = [Value::new(1.0, None)];
+        let targets = [Value::new(1.0, None)];
+        let loss = bce(&predictions, &targets);
+        assert!(loss.data() < 1e-4);
+    }
+
+    #[test]
+    fn test_bce_matches_closed_form() {
+        let predictions = [Value::new(0.8, None)];
+        let targets = [Value::new(1.0, None)];
+        let loss = bce(&predictions, &targets);
+        let expected = -(0.8f32.ln());
+        assert!((loss.data() - expected).abs() < 1e-4);
+    }
+}
@@ -2,36 +2,65 @@ use rand::Rng;
 
 use crate::Value;
 
+/// A nonlinearity applied to a neuron's weighted sum.
+///
+/// `Tanh`, `ReLU` and `Sigmoid` dispatch to the matching `Value` method;
+/// `Identity` passes the weighted sum through unchanged, which is typically
+/// what an output layer wants when paired with a loss that expects raw
+/// logits (or when the output layer already applies its own `Sigmoid`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Activation {
+    Tanh,
+    ReLU,
+    Sigmoid,
+    Identity,
+}
+
+impl Activation {
+    /// Applies this activation to a neuron's weighted sum.
+    fn forward(&self, x: Value) -> Value {
+        match self {
+            Activation::Tanh => x.tanh(),
+            Activation::ReLU => x.relu(),
+            Activation::Sigmoid => x.sigmoid(),
+            Activation::Identity => x,
+        }
+    }
+}
+
 /// Represents a single neuron in a neural network.
 pub struct Neuron {
-    pub w: Vec<Value>, // Weights of the neuron
-    pub b: Value,      // Bias of the neuron
+    pub w: Vec<Value>,           // Weights of the neuron
+    pub b: Value,                // Bias of the neuron
+    pub activation: Activation,  // Nonlinearity applied to the weighted sum
 }
 
 impl Neuron {
     /// Creates a new neuron with random weights and bias.
-    pub fn new(num_inputs: usize) -> Neuron {
+    pub fn new(num_inputs: usize, activation: Activation) -> Neuron {
         let mut rng = rand::thread_rng();
         let w = (0..num_inputs)
             .map(|_| Value::new(rng.gen_range(-1.0..1.0), None))
             .collect();
         let b = Value::new(rng.gen_range(-1.0..1.0), None);
-        Neuron { w, b }
+        Neuron { w, b, activation }
     }
 
     /// Performs the forward pass of the neuron.
     pub fn forward(&self, x: &[Value]) -> Value {
-        x.iter()
+        let sum = x
+            .iter()
             .zip(&self.w)
             .map(|(a, b)| a * b)
-            .fold(self.b.clone(), |acc, v| &acc + &v)
-            .tanh()
+            .fold(self.b.clone(), |acc, v| &acc + &v);
+        self.activation.forward(sum)
     }
 
     /// Returns the parameters (weights and bias) of the neuron.
-    pub fn parameters(&self) -> Vec<&Value> {
-        let mut res = self.w.iter().collect::<Vec<_>>();
-        res.push(&self.b);
+    pub fn parameters(&self) -> Vec<Value> {
+        let mut res = self.w.clone();
+        res.push(self.b.clone());
         res
     }
 }
@@ -43,22 +72,41 @@ pub struct Layer {
 
 impl Layer {
     /// Creates a new layer with the specified number of inputs and outputs.
-    pub fn new(num_inputs: usize, num_outputs: usize) -> Layer {
-        let neurons = (0..num_outputs).map(|_| Neuron::new(num_inputs)).collect();
+    pub fn new(num_inputs: usize, num_outputs: usize, activation: Activation) -> Layer {
+        let neurons = (0..num_outputs)
+            .map(|_| Neuron::new(num_inputs, activation))
+            .collect();
         Layer { neurons }
     }
 
     /// Performs the forward pass of the layer.
+    ///
+    /// Each neuron's forward pass is independent of the others, so with the
+    /// `rayon` feature enabled this runs over `self.neurons` on the global
+    /// rayon thread pool instead of sequentially. That feature also
+    /// switches `Value`'s shared inner cell from `Rc<RefCell<_>>` to
+    /// `Arc<RwLock<_>>` (see `crate::cell`), since the resulting graph
+    /// nodes - and the `x` values they read - must cross thread boundaries
+    /// to make that safe. Without the feature, this is a plain sequential
+    /// map, as before.
+    #[cfg(feature = "rayon")]
+    pub fn forward(&self, x: &[Value]) -> Vec<Value> {
+        use rayon::prelude::*;
+
+        self.neurons.par_iter().map(|n| n.forward(x)).collect()
+    }
+
+    /// Performs the forward pass of the layer.
+    #[cfg(not(feature = "rayon"))]
     pub fn forward(&self, x: &[Value]) -> Vec<Value> {
         self.neurons.iter().map(|n| n.forward(x)).collect()
     }
 
     /// Returns the parameters (weights and biases) of the layer.
-    pub fn parameters(&self) -> Vec<&Value> {
+    pub fn parameters(&self) -> Vec<Value> {
         self.neurons
             .iter()
-            .map(|n| n.parameters())
-            .flatten()
+            .flat_map(|n| n.parameters())
             .collect()
     }
 }
@@ -70,8 +118,28 @@ pub struct MultiLayerPerceptron {
 }
 
 impl MultiLayerPerceptron {
-    /// Creates a new multi-layer perceptron with the specified number of inputs and layer sizes.
-    pub fn new(num_inputs: usize, layer_sizes: &[usize]) -> MultiLayerPerceptron {
+    /// Creates a new multi-layer perceptron with the specified number of
+    /// inputs, layer sizes and per-layer activations.
+    ///
+    /// `layer_sizes` and `activations` must be the same length - one entry
+    /// per layer, including the output layer. This lets e.g. ReLU hidden
+    /// layers be paired with a linear (`Activation::Identity`) or sigmoid
+    /// output layer instead of applying the same nonlinearity everywhere.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `layer_sizes.len() != activations.len()`.
+    pub fn new(
+        num_inputs: usize,
+        layer_sizes: &[usize],
+        activations: &[Activation],
+    ) -> MultiLayerPerceptron {
+        assert_eq!(
+            layer_sizes.len(),
+            activations.len(),
+            "layer_sizes and activations must have the same length"
+        );
+
         let mut sizes = vec![num_inputs];
         sizes.extend(layer_sizes);
 
@@ -81,7 +149,10 @@ impl MultiLayerPerceptron {
         let layers = inputs
             .iter()
             .zip(outputs)
-            .map(|(num_inputs, num_outputs)| Layer::new(*num_inputs, *num_outputs))
+            .zip(activations)
+            .map(|((num_inputs, num_outputs), activation)| {
+                Layer::new(*num_inputs, *num_outputs, *activation)
+            })
             .collect();
         MultiLayerPerceptron { layers, sizes }
     }
@@ -96,11 +167,10 @@ impl MultiLayerPerceptron {
     }
 
     /// Returns the parameters (weights and biases) of the multi-layer perceptron.
-    pub fn parameters(&self) -> Vec<&Value> {
+    pub fn parameters(&self) -> Vec<Value> {
         self.layers
             .iter()
-            .map(|n| n.parameters())
-            .flatten()
+            .flat_map(|n| n.parameters())
             .collect()
     }
 }
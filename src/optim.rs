@@ -0,0 +1,114 @@
+use crate::Value;
+
+/// A strategy for updating a model's parameters from their accumulated
+/// gradients.
+///
+/// The usual training-loop shape is `opt.zero_grad(); loss.backward();
+/// opt.step();`, repeated once per batch.
+pub trait Optimizer {
+    /// Resets every parameter's gradient to zero, ready for the next
+    /// `backward()` pass.
+    fn zero_grad(&self);
+
+    /// Applies one parameter update using each parameter's current
+    /// gradient.
+    fn step(&mut self);
+}
+
+/// Stochastic gradient descent with optional classical momentum and L2
+/// weight decay.
+///
+/// Construct once from a model's `parameters()` and drive it around the
+/// training loop: `opt.zero_grad(); loss.backward(); opt.step();` in place
+/// of hand-written `data -= lr * grad` updates.
+pub struct Sgd {
+    params: Vec<Value>,
+    lr: f32,
+    momentum: f32,
+    weight_decay: f32,
+    velocity: Vec<f32>,
+}
+
+impl Sgd {
+    /// Creates a new `Sgd` optimizer over `params`.
+    ///
+    /// * `lr` - the learning rate.
+    /// * `momentum` - classical momentum coefficient `μ`; `0.0` disables
+    ///   momentum, reducing `step()` to plain gradient descent.
+    /// * `weight_decay` - L2 weight decay coefficient `λ`, added into the
+    ///   gradient before the update; `0.0` disables it.
+    pub fn new(params: Vec<Value>, lr: f32, momentum: f32, weight_decay: f32) -> Sgd {
+        let velocity = vec![0.0; params.len()];
+        Sgd {
+            params,
+            lr,
+            momentum,
+            weight_decay,
+            velocity,
+        }
+    }
+}
+
+impl Optimizer for Sgd {
+    fn zero_grad(&self) {
+        for p in &self.params {
+            p.set_grad(0.0);
+        }
+    }
+
+    fn step(&mut self) {
+        for (p, v) in self.params.iter().zip(self.velocity.iter_mut()) {
+            let grad = p.grad() + self.weight_decay * p.data();
+            *v = self.momentum * *v - self.lr * grad;
+            p.set_data(p.data() + *v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_grad() {
+        let a = Value::new(1.0, None);
+        a.set_grad(2.0);
+        let opt = Sgd::new(vec![a.clone()], 0.1, 0.0, 0.0);
+        opt.zero_grad();
+        assert_eq!(a.grad(), 0.0);
+    }
+
+    #[test]
+    fn test_step_plain_sgd() {
+        let a = Value::new(1.0, None);
+        a.set_grad(2.0);
+        let mut opt = Sgd::new(vec![a.clone()], 0.1, 0.0, 0.0);
+        opt.step();
+        assert!((a.data() - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_step_with_momentum() {
+        let a = Value::new(1.0, None);
+        a.set_grad(1.0);
+        let mut opt = Sgd::new(vec![a.clone()], 0.1, 0.9, 0.0);
+        opt.step();
+        // v = 0.9 * 0 - 0.1 * 1.0 = -0.1
+        assert!((a.data() - 0.9).abs() < 1e-6);
+
+        a.set_grad(1.0);
+        opt.step();
+        // v = 0.9 * -0.1 - 0.1 * 1.0 = -0.19
+        assert!((a.data() - 0.71).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_step_with_weight_decay() {
+        let a = Value::new(1.0, None);
+        a.set_grad(0.0);
+        let mut opt = Sgd::new(vec![a.clone()], 0.1, 0.0, 0.5);
+        opt.step();
+        // grad = 0.0 + 0.5 * 1.0 = 0.5; v = -0.1 * 0.5 = -0.05
+        assert!((a.data() - 0.95).abs() < 1e-6);
+    }
+}
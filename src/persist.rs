@@ -0,0 +1,179 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Activation, Layer, MultiLayerPerceptron, Neuron, Value};
+
+/// Plain-data snapshot of a single neuron's weights and bias, in the same
+/// order as `Neuron::w`.
+///
+/// `Value` wraps a shared `ValueInner` cell (`Rc<RefCell<_>>`, or
+/// `Arc<RwLock<_>>` with the `rayon` feature), which cannot derive
+/// `Serialize`/`Deserialize` on its own (it's shared, interior-mutable
+/// graph state, not plain data) - this DTO carries just the `f32`s needed
+/// to reconstruct an equivalent neuron.
+#[derive(Serialize, Deserialize)]
+struct NeuronDto {
+    w: Vec<f32>,
+    b: f32,
+}
+
+/// Plain-data snapshot of a `Layer`: its neurons plus the single
+/// `Activation` they all share.
+#[derive(Serialize, Deserialize)]
+struct LayerDto {
+    activation: Activation,
+    neurons: Vec<NeuronDto>,
+}
+
+/// Plain-data snapshot of a `MultiLayerPerceptron`'s architecture and
+/// parameters, suitable for serializing to and loading back from JSON.
+#[derive(Serialize, Deserialize)]
+struct MlpDto {
+    sizes: Vec<usize>,
+    layers: Vec<LayerDto>,
+}
+
+impl From<&MultiLayerPerceptron> for MlpDto {
+    fn from(mlp: &MultiLayerPerceptron) -> Self {
+        let layers = mlp
+            .layers
+            .iter()
+            .map(|layer| LayerDto {
+                activation: layer
+                    .neurons
+                    .first()
+                    .map(|n| n.activation)
+                    .unwrap_or(Activation::Identity),
+                neurons: layer
+                    .neurons
+                    .iter()
+                    .map(|n| NeuronDto {
+                        w: n.w.iter().map(Value::data).collect(),
+                        b: n.b.data(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        MlpDto {
+            sizes: mlp.sizes.clone(),
+            layers,
+        }
+    }
+}
+
+impl From<MlpDto> for MultiLayerPerceptron {
+    fn from(dto: MlpDto) -> Self {
+        assert_eq!(
+            dto.sizes.len(),
+            dto.layers.len() + 1,
+            "sizes must have exactly one more entry than layers"
+        );
+
+        let sizes = dto.sizes;
+        let layers = dto
+            .layers
+            .into_iter()
+            .enumerate()
+            .map(|(i, layer_dto)| {
+                let expected_inputs = sizes[i];
+                let expected_outputs = sizes[i + 1];
+                assert_eq!(
+                    layer_dto.neurons.len(),
+                    expected_outputs,
+                    "layer {i} has {} neurons, expected {expected_outputs}",
+                    layer_dto.neurons.len()
+                );
+
+                let neurons = layer_dto
+                    .neurons
+                    .into_iter()
+                    .map(|n| {
+                        assert_eq!(
+                            n.w.len(),
+                            expected_inputs,
+                            "a neuron in layer {i} has {} weights, expected {expected_inputs}",
+                            n.w.len()
+                        );
+                        Neuron {
+                            w: n.w.into_iter().map(|v| Value::new(v, None)).collect(),
+                            b: Value::new(n.b, None),
+                            activation: layer_dto.activation,
+                        }
+                    })
+                    .collect();
+                Layer { neurons }
+            })
+            .collect();
+
+        MultiLayerPerceptron { layers, sizes }
+    }
+}
+
+impl MultiLayerPerceptron {
+    /// Serializes this network's architecture and parameters to a JSON
+    /// string.
+    ///
+    /// Only `sizes` plus each neuron's weights, bias and layer activation
+    /// are captured - the computation graph (operations, cached gradients)
+    /// is not part of the saved state, since `load` reconstructs it from
+    /// scratch with `Op::None` leaves.
+    pub fn save(&self) -> serde_json::Result<String> {
+        serde_json::to_string(&MlpDto::from(self))
+    }
+
+    /// Reconstructs a `MultiLayerPerceptron` from JSON produced by `save`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the decoded layer shapes are inconsistent with `sizes`,
+    /// i.e. `sizes` doesn't have exactly one more entry than there are
+    /// layers, some layer doesn't have as many neurons as `sizes` calls for,
+    /// or some neuron doesn't have as many weights as the previous layer
+    /// has outputs (or, for the first layer, as `sizes[0]` inputs).
+    pub fn load(json: &str) -> serde_json::Result<MultiLayerPerceptron> {
+        let dto: MlpDto = serde_json::from_str(json)?;
+        Ok(dto.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let mlp = MultiLayerPerceptron::new(
+            3,
+            &[4, 1],
+            &[Activation::ReLU, Activation::Sigmoid],
+        );
+
+        let json = mlp.save().expect("serialization should succeed");
+        let loaded = MultiLayerPerceptron::load(&json).expect("deserialization should succeed");
+
+        assert_eq!(loaded.sizes, mlp.sizes);
+
+        let x: Vec<Value> = vec![
+            Value::new(1.0, None),
+            Value::new(-2.0, None),
+            Value::new(0.5, None),
+        ];
+        let original_out = mlp.forward(&x);
+        let loaded_out = loaded.forward(&x);
+        assert_eq!(
+            original_out.iter().map(Value::data).collect::<Vec<_>>(),
+            loaded_out.iter().map(Value::data).collect::<Vec<_>>(),
+        );
+
+        for p in loaded.parameters() {
+            assert_eq!(p.grad(), 0.0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_load_rejects_inconsistent_shape() {
+        let json = r#"{"sizes":[3,1],"layers":[{"activation":"Tanh","neurons":[{"w":[1.0,2.0],"b":0.0}]}]}"#;
+        MultiLayerPerceptron::load(json).unwrap();
+    }
+}
@@ -1,6 +1,8 @@
-use std::{cell::RefCell, fmt::Display, ops, rc::Rc};
+use std::{collections::HashSet, fmt::Display, ops};
 
-pub type ValueInnerRef = Rc<RefCell<ValueInner>>;
+use crate::cell::Shared;
+
+pub type ValueInnerRef = Shared<ValueInner>;
 
 /// Represents different operations that can be performed on a `Value`.
 #[derive(Clone, Debug)]
@@ -10,6 +12,11 @@ pub enum Op {
     Mul(ValueInnerRef, ValueInnerRef),
     TanH(ValueInnerRef, f32),
     Pow(ValueInnerRef, f32),
+    ReLU(ValueInnerRef),
+    Sigmoid(ValueInnerRef, f32),
+    Exp(ValueInnerRef, f32),
+    Ln(ValueInnerRef),
+    Clamp(ValueInnerRef, f32, f32),
 }
 
 impl Display for Op {
@@ -19,6 +26,11 @@ impl Display for Op {
             Op::Mul(_, _) => write!(f, "*")?,
             Op::TanH(_, _) => write!(f, "tanh")?,
             Op::Pow(_, _) => write!(f, "pow")?,
+            Op::ReLU(_) => write!(f, "relu")?,
+            Op::Sigmoid(_, _) => write!(f, "sigmoid")?,
+            Op::Exp(_, _) => write!(f, "exp")?,
+            Op::Ln(_) => write!(f, "ln")?,
+            Op::Clamp(_, _, _) => write!(f, "clamp")?,
             _ => {}
         };
         Ok(())
@@ -48,37 +60,64 @@ impl ValueInner {
     ///
     /// A `ValueInnerRef` reference to the newly created `ValueInner` instance.
     pub fn new(data: f32, op: Op, grad: f32, label: Option<&'static str>) -> ValueInnerRef {
-        Rc::new(RefCell::new(ValueInner {
+        Shared::new(ValueInner {
             data,
             op,
             grad,
             label,
-        }))
+        })
     }
 
-    /// Performs backward propagation of gradients for the value.
-    pub fn backward(&self) {
+    /// Applies this node's local gradient rule to its immediate operands.
+    ///
+    /// Pushes `self.grad` into each operand's `grad` using only the local
+    /// derivative for this node's `op`. Does not recurse - callers are
+    /// expected to visit nodes in reverse topological order themselves (see
+    /// `Value::backward`).
+    fn propagate(&self) {
         match &self.op {
             Op::Add(a, b) => {
                 a.borrow_mut().grad += 1.0 * self.grad;
                 b.borrow_mut().grad += 1.0 * self.grad;
-                a.borrow().backward();
-                b.borrow().backward();
             }
             Op::Mul(a, b) => {
-                a.borrow_mut().grad += b.borrow().data * self.grad;
-                b.borrow_mut().grad += a.borrow().data * self.grad;
-                a.borrow().backward();
-                b.borrow().backward();
+                // Read both operands' data before taking any mutable borrow:
+                // `a` and `b` may be the same shared node (e.g. `x * x`), in
+                // which case interleaving a mutable borrow with a read on the
+                // same cell would misbehave - panicking on the default
+                // `Rc<RefCell<_>>` backend, or deadlocking on the `rayon`
+                // feature's `Arc<RwLock<_>>` backend (see `crate::cell`).
+                let a_data = a.borrow().data;
+                let b_data = b.borrow().data;
+                a.borrow_mut().grad += b_data * self.grad;
+                b.borrow_mut().grad += a_data * self.grad;
             }
             Op::TanH(a, t) => {
                 a.borrow_mut().grad += (1.0 - t.powf(2.0)) * self.grad;
-                a.borrow().backward();
             }
             Op::Pow(a, b) => {
                 let v = a.borrow().data.powf(b - 1.0);
                 a.borrow_mut().grad += b * v * self.grad;
-                a.borrow().backward();
+            }
+            Op::ReLU(a) if a.borrow().data > 0.0 => {
+                a.borrow_mut().grad += self.grad;
+            }
+            Op::ReLU(_) => {}
+            Op::Sigmoid(a, s) => {
+                a.borrow_mut().grad += s * (1.0 - s) * self.grad;
+            }
+            Op::Exp(a, out) => {
+                a.borrow_mut().grad += out * self.grad;
+            }
+            Op::Ln(a) => {
+                let x = a.borrow().data;
+                a.borrow_mut().grad += self.grad / x;
+            }
+            Op::Clamp(a, min, max) => {
+                let x = a.borrow().data;
+                if x > *min && x < *max {
+                    a.borrow_mut().grad += self.grad;
+                }
             }
             _ => {}
         }
@@ -91,7 +130,7 @@ impl ValueInner {
 /// It is part of a simple automatic differentiation framework implemented in RustyMicroGrad.
 #[derive(Clone, Debug)]
 pub struct Value {
-    pub inner: Rc<RefCell<ValueInner>>,
+    pub inner: ValueInnerRef,
 }
 
 impl Value {
@@ -107,12 +146,12 @@ impl Value {
     /// A `Value` instance.
     pub fn new(data: f32, label: Option<&'static str>) -> Value {
         Value {
-            inner: Rc::new(RefCell::new(ValueInner {
+            inner: Shared::new(ValueInner {
                 data,
                 op: Op::None,
                 grad: 0.0,
                 label,
-            })),
+            }),
         }
     }
 
@@ -150,9 +189,154 @@ impl Value {
         }
     }
 
-    /// Performs backward propagation of gradients for the value.
+    /// Computes the rectified linear unit of the value: `max(0, x)`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Value` instance representing the ReLU of the original value.
+    pub fn relu(&self) -> Value {
+        let x = self.inner.borrow().data;
+        let out = x.max(0.0);
+        let op = Op::ReLU(self.inner.clone());
+        Value {
+            inner: ValueInner::new(out, op, 0.0, Some("relu")),
+        }
+    }
+
+    /// Computes the sigmoid (logistic) function of the value.
+    ///
+    /// # Returns
+    ///
+    /// A new `Value` instance representing the sigmoid of the original value.
+    pub fn sigmoid(&self) -> Value {
+        let x = self.inner.borrow().data;
+        let s = 1.0 / (1.0 + f32::exp(-x));
+        let op = Op::Sigmoid(self.inner.clone(), s);
+        Value {
+            inner: ValueInner::new(s, op, 0.0, Some("sigmoid")),
+        }
+    }
+
+    /// Computes `e` raised to the power of the value.
+    ///
+    /// # Returns
+    ///
+    /// A new `Value` instance representing `e^x` for the original value.
+    pub fn exp(&self) -> Value {
+        let x = self.inner.borrow().data;
+        let out = f32::exp(x);
+        let op = Op::Exp(self.inner.clone(), out);
+        Value {
+            inner: ValueInner::new(out, op, 0.0, Some("exp")),
+        }
+    }
+
+    /// Computes the natural logarithm of the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value's data is not strictly positive, since `ln` has
+    /// no real result (and no defined gradient) for `x <= 0`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Value` instance representing the natural logarithm of the
+    /// original value.
+    pub fn ln(&self) -> Value {
+        let x = self.inner.borrow().data;
+        assert!(x > 0.0, "ln() requires a strictly positive value, got {x}");
+        let op = Op::Ln(self.inner.clone());
+        Value {
+            inner: ValueInner::new(x.ln(), op, 0.0, Some("ln")),
+        }
+    }
+
+    /// Clamps the value to the inclusive range `[min, max]`.
+    ///
+    /// Behaves like `ReLU` on the boundary: the local gradient passes
+    /// through unchanged for inputs inside the range and is zero for inputs
+    /// outside it. Useful for keeping a value numerically safe for a
+    /// follow-on operation like `ln` (e.g. clamping a sigmoid output away
+    /// from exactly `0.0`/`1.0`) without distorting the gradient for the
+    /// common case where clamping is a no-op.
+    ///
+    /// # Returns
+    ///
+    /// A new `Value` instance representing the original value clamped to
+    /// `[min, max]`.
+    pub fn clamp(&self, min: f32, max: f32) -> Value {
+        let x = self.inner.borrow().data;
+        let out = x.clamp(min, max);
+        let op = Op::Clamp(self.inner.clone(), min, max);
+        Value {
+            inner: ValueInner::new(out, op, 0.0, Some("clamp")),
+        }
+    }
+
+    /// Performs a full reverse-mode backward pass from this value.
+    ///
+    /// Builds a topological ordering of every node reachable from `self` via
+    /// a depth-first traversal, using the raw pointer identity of each
+    /// node's shared cell to visit each node exactly once even when it
+    /// is shared by multiple parents (as happens whenever an input or
+    /// parameter feeds more than one downstream computation - every MLP does
+    /// this). This value's own grad is then seeded with `1.0`, and the
+    /// topological order is walked in reverse, applying each node's local
+    /// gradient rule exactly once. This makes `backward()` O(nodes + edges)
+    /// instead of exponential in graph depth.
+    ///
+    /// Grads are accumulated (`+=`), not reset, so calling `backward()`
+    /// does not zero anything first - a fresh forward pass always produces
+    /// fresh nodes starting at grad `0.0`, and a long-lived leaf (a
+    /// parameter reused across iterations) keeps whatever grad it already
+    /// had. This matches the usual `opt.zero_grad(); loss.backward();
+    /// opt.step();` idiom: `zero_grad()` is what resets parameter grads
+    /// between steps, and skipping it on purpose (e.g. to accumulate
+    /// gradients over several mini-batches before a single `step()`) works
+    /// as expected.
     pub fn backward(&self) {
-        self.inner.borrow().backward()
+        let mut visited = HashSet::new();
+        let mut topo = Vec::new();
+        Self::build_topo(&self.inner, &mut visited, &mut topo);
+
+        self.inner.borrow_mut().grad += 1.0;
+
+        for node in topo.iter().rev() {
+            node.borrow().propagate();
+        }
+    }
+
+    /// Depth-first traversal collecting `inner`'s dependency graph into `topo`
+    /// in topological order (operands before the values that consume them).
+    ///
+    /// `visited` is keyed by the raw pointer identity of each node's shared
+    /// cell so that a node reachable through several paths is still only
+    /// added to `topo` once.
+    fn build_topo(
+        inner: &ValueInnerRef,
+        visited: &mut HashSet<*const ()>,
+        topo: &mut Vec<ValueInnerRef>,
+    ) {
+        if !visited.insert(inner.as_ptr()) {
+            return;
+        }
+        match &inner.borrow().op {
+            Op::Add(a, b) | Op::Mul(a, b) => {
+                Self::build_topo(a, visited, topo);
+                Self::build_topo(b, visited, topo);
+            }
+            Op::TanH(a, _) | Op::Pow(a, _) | Op::Sigmoid(a, _) | Op::Exp(a, _) => {
+                Self::build_topo(a, visited, topo);
+            }
+            Op::ReLU(a) | Op::Ln(a) => {
+                Self::build_topo(a, visited, topo);
+            }
+            Op::Clamp(a, _, _) => {
+                Self::build_topo(a, visited, topo);
+            }
+            Op::None => {}
+        }
+        topo.push(inner.clone());
     }
 
     pub fn data(&self) -> f32 {
@@ -335,9 +519,102 @@ mod tests {
         let a = Value::new(2.0, Some("a"));
         let b = Value::new(3.0, Some("b"));
         let c = &a * &b;
-        c.set_grad(1.0);
         c.backward();
         assert_eq!(a.grad(), 3.0);
         assert_eq!(b.grad(), 2.0);
     }
+
+    #[test]
+    fn test_backward_shared_subexpression() {
+        // d = (a + a) + (a * a), where `a` is shared by four edges. A naive
+        // recursive backward would visit `a` repeatedly and double-count;
+        // the correct local gradient is 2 + 2*a = 2 + 4 = 6.
+        let a = Value::new(2.0, Some("a"));
+        let sum = &a + &a;
+        let prod = &a * &a;
+        let d = &sum + &prod;
+        d.backward();
+        assert_eq!(a.grad(), 6.0);
+    }
+
+    #[test]
+    fn test_backward_accumulates_across_calls() {
+        // Two backward() passes over the same leaf without an intervening
+        // zero_grad() accumulate, like the standard "accumulate grads over
+        // several mini-batches, then step()" idiom relies on.
+        let a = Value::new(2.0, Some("a"));
+        let b = Value::new(3.0, Some("b"));
+
+        let c1 = &a * &b;
+        c1.backward();
+        assert_eq!(a.grad(), 3.0);
+
+        let c2 = &a * &b;
+        c2.backward();
+        assert_eq!(a.grad(), 6.0);
+    }
+
+    #[test]
+    fn test_relu() {
+        let a = Value::new(-2.0, Some("a"));
+        let b = a.relu();
+        assert_eq!(b.data(), 0.0);
+        b.backward();
+        assert_eq!(a.grad(), 0.0);
+
+        let c = Value::new(3.0, Some("c"));
+        let d = c.relu();
+        assert_eq!(d.data(), 3.0);
+        d.backward();
+        assert_eq!(c.grad(), 1.0);
+    }
+
+    #[test]
+    fn test_sigmoid() {
+        let a = Value::new(0.0, Some("a"));
+        let b = a.sigmoid();
+        assert_eq!(b.data(), 0.5);
+        b.backward();
+        assert_eq!(a.grad(), 0.25);
+    }
+
+    #[test]
+    fn test_exp() {
+        let a = Value::new(1.0, Some("a"));
+        let b = a.exp();
+        assert!((b.data() - std::f32::consts::E).abs() < 0.0001);
+        b.backward();
+        assert!((a.grad() - std::f32::consts::E).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_ln() {
+        let a = Value::new(std::f32::consts::E, Some("a"));
+        let b = a.ln();
+        assert!((b.data() - 1.0).abs() < 0.0001);
+        b.backward();
+        assert!((a.grad() - 1.0 / std::f32::consts::E).abs() < 0.0001);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ln_non_positive_panics() {
+        let a = Value::new(0.0, Some("a"));
+        a.ln();
+    }
+
+    #[test]
+    fn test_clamp() {
+        let a = Value::new(5.0, Some("a"));
+        let b = a.clamp(0.0, 1.0);
+        assert_eq!(b.data(), 1.0);
+        b.backward();
+        assert_eq!(a.grad(), 0.0);
+
+        let c = Value::new(0.5, Some("c"));
+        let d = c.clamp(0.0, 1.0);
+        assert_eq!(d.data(), 0.5);
+        d.backward();
+        assert_eq!(c.grad(), 1.0);
+    }
 }